@@ -13,8 +13,8 @@ use azure_core_amqp::{
         builders::AmqpMessageBuilder, AmqpAnnotationKey, AmqpAnnotations,
         AmqpApplicationProperties, AmqpMessage, AmqpMessageBody,
     },
-    value::{AmqpOrderedMap, AmqpValue},
-    Deserializable,
+    value::{AmqpOrderedMap, AmqpTimestamp, AmqpValue},
+    Deserializable, Serializable,
 };
 use std::mem;
 use tracing::warn;
@@ -101,6 +101,58 @@ unsafe extern "C" fn message_deserialize(
     }
 }
 
+// NOT IMPLEMENTED: decoding a single message out of a buffer that holds several
+// back-to-back encoded messages (e.g. draining a transfer frame payload) and reporting
+// how many bytes it consumed, so the caller can advance a cursor and decode the next one
+// in a loop, needs a decoder that can stop after one message without being handed its
+// exact length up front. `Deserializable::decode` (used by `message_deserialize` above)
+// only accepts a buffer holding exactly one message and has no such partial/framing
+// mode, and there's no reliable way to fake "bytes consumed" or "truncated vs malformed"
+// on top of it without risking silently dropping the messages after the first one. This
+// needs a real single-message framing/streaming decode added to `azure_core_amqp` first;
+// until then there's no entry point here for it.
+
+// Serializes `message` into its AMQP 1.0 wire encoding, following the canonical section
+// order (header, delivery-annotations, message-annotations, properties,
+// application-properties, body, footer) and omitting any section that is absent.
+//
+// On success, `*buffer` receives a Rust-owned buffer of `*buffer_size` bytes which the
+// caller must release via `message_serialize_destroy_buffer`.
+#[no_mangle]
+unsafe extern "C" fn message_serialize(
+    message: *const RustAmqpMessage,
+    buffer: *mut *mut u8,
+    buffer_size: *mut usize,
+) -> i32 {
+    let message = &*message;
+    *buffer = std::ptr::null_mut();
+    *buffer_size = 0;
+    let encoded_size = match message.inner.encoded_size() {
+        Ok(size) => size,
+        Err(err) => {
+            warn!("Failed to compute serialized message size: {:?}", err);
+            return 1;
+        }
+    };
+    let mut encoded = vec![0u8; encoded_size].into_boxed_slice();
+    if let Err(err) = message.inner.serialize(&mut encoded) {
+        warn!("Failed to serialize message: {:?}", err);
+        return 1;
+    }
+    *buffer_size = encoded.len();
+    *buffer = encoded.as_mut_ptr();
+    mem::forget(encoded);
+    0
+}
+
+#[no_mangle]
+unsafe extern "C" fn message_serialize_destroy_buffer(buffer: *mut u8, buffer_size: usize) {
+    mem::drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+        buffer,
+        buffer_size,
+    )));
+}
+
 #[no_mangle]
 unsafe extern "C" fn message_get_header(
     message: *const RustAmqpMessage,
@@ -157,6 +209,83 @@ unsafe extern "C" fn message_get_delivery_annotations(
     }
 }
 
+// A forward-only cursor over one of a message's annotation/application-property maps.
+// Created once per map via `message_*_iterator_create` (a single clone of the map's
+// entries), then advanced with `message_map_iterator_next`, which is O(1) amortized per
+// entry rather than the O(index) an index-based accessor would need to pay on every
+// call to re-walk the map from the start.
+pub struct RustAmqpMessageMapIterator {
+    entries: Vec<(AmqpValue, AmqpValue)>,
+    position: usize,
+}
+
+#[no_mangle]
+unsafe extern "C" fn message_map_iterator_next(
+    iterator: *mut RustAmqpMessageMapIterator,
+    key: *mut *mut RustAmqpValue,
+    value: *mut *mut RustAmqpValue,
+) -> i32 {
+    let iterator = &mut *iterator;
+    match iterator.entries.get(iterator.position) {
+        Some((k, v)) => {
+            *key = Box::into_raw(Box::new(RustAmqpValue { inner: k.clone() }));
+            *value = Box::into_raw(Box::new(RustAmqpValue { inner: v.clone() }));
+            iterator.position += 1;
+            0
+        }
+        None => 1,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn message_map_iterator_destroy(iterator: *mut RustAmqpMessageMapIterator) {
+    mem::drop(Box::from_raw(iterator));
+}
+
+#[no_mangle]
+unsafe extern "C" fn message_get_delivery_annotations_count(
+    message: *const RustAmqpMessage,
+    count: &mut usize,
+) -> i32 {
+    let message = &*message;
+    match message.inner.delivery_annotations() {
+        Some(da) => {
+            *count = da.0.len();
+            0
+        }
+        None => {
+            *count = 0;
+            1
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn message_delivery_annotations_iterator_create(
+    message: *const RustAmqpMessage,
+    iterator: *mut *mut RustAmqpMessageMapIterator,
+) -> i32 {
+    let message = &*message;
+    match message.inner.delivery_annotations() {
+        Some(da) => {
+            let entries = da
+                .0
+                .iter()
+                .map(|(k, v)| (AmqpValue::from(k.clone()), v.clone()))
+                .collect();
+            *iterator = Box::into_raw(Box::new(RustAmqpMessageMapIterator {
+                entries,
+                position: 0,
+            }));
+            0
+        }
+        None => {
+            *iterator = std::ptr::null_mut();
+            1
+        }
+    }
+}
+
 #[no_mangle]
 unsafe extern "C" fn message_get_message_annotations(
     message: *const RustAmqpMessage,
@@ -183,6 +312,50 @@ unsafe extern "C" fn message_get_message_annotations(
     }
 }
 
+#[no_mangle]
+unsafe extern "C" fn message_get_message_annotations_count(
+    message: *const RustAmqpMessage,
+    count: &mut usize,
+) -> i32 {
+    let message = &*message;
+    match message.inner.message_annotations() {
+        Some(da) => {
+            *count = da.0.len();
+            0
+        }
+        None => {
+            *count = 0;
+            1
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn message_message_annotations_iterator_create(
+    message: *const RustAmqpMessage,
+    iterator: *mut *mut RustAmqpMessageMapIterator,
+) -> i32 {
+    let message = &*message;
+    match message.inner.message_annotations() {
+        Some(da) => {
+            let entries = da
+                .0
+                .iter()
+                .map(|(k, v)| (k.clone().into(), v.clone()))
+                .collect();
+            *iterator = Box::into_raw(Box::new(RustAmqpMessageMapIterator {
+                entries,
+                position: 0,
+            }));
+            0
+        }
+        None => {
+            *iterator = std::ptr::null_mut();
+            1
+        }
+    }
+}
+
 #[no_mangle]
 unsafe extern "C" fn message_get_application_properties(
     message: *const RustAmqpMessage,
@@ -210,6 +383,50 @@ unsafe extern "C" fn message_get_application_properties(
     }
 }
 
+#[no_mangle]
+unsafe extern "C" fn message_get_application_properties_count(
+    message: *const RustAmqpMessage,
+    count: &mut usize,
+) -> i32 {
+    let message = &*message;
+    match message.inner.application_properties() {
+        Some(da) => {
+            *count = da.0.len();
+            0
+        }
+        None => {
+            *count = 0;
+            1
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn message_application_properties_iterator_create(
+    message: *const RustAmqpMessage,
+    iterator: *mut *mut RustAmqpMessageMapIterator,
+) -> i32 {
+    let message = &*message;
+    match message.inner.application_properties() {
+        Some(da) => {
+            let entries = da
+                .0
+                .iter()
+                .map(|(k, v)| (AmqpValue::String(k.clone()), v.clone()))
+                .collect();
+            *iterator = Box::into_raw(Box::new(RustAmqpMessageMapIterator {
+                entries,
+                position: 0,
+            }));
+            0
+        }
+        None => {
+            *iterator = std::ptr::null_mut();
+            1
+        }
+    }
+}
+
 #[no_mangle]
 unsafe extern "C" fn message_get_footer(
     message: *const RustAmqpMessage,
@@ -237,6 +454,50 @@ unsafe extern "C" fn message_get_footer(
     }
 }
 
+#[no_mangle]
+unsafe extern "C" fn message_get_footer_count(
+    message: *const RustAmqpMessage,
+    count: &mut usize,
+) -> i32 {
+    let message = &*message;
+    match message.inner.footer() {
+        Some(da) => {
+            *count = da.0.len();
+            0
+        }
+        None => {
+            *count = 0;
+            1
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn message_footer_iterator_create(
+    message: *const RustAmqpMessage,
+    iterator: *mut *mut RustAmqpMessageMapIterator,
+) -> i32 {
+    let message = &*message;
+    match message.inner.footer() {
+        Some(da) => {
+            let entries = da
+                .0
+                .iter()
+                .map(|(k, v)| (k.clone().into(), v.clone()))
+                .collect();
+            *iterator = Box::into_raw(Box::new(RustAmqpMessageMapIterator {
+                entries,
+                position: 0,
+            }));
+            0
+        }
+        None => {
+            *iterator = std::ptr::null_mut();
+            1
+        }
+    }
+}
+
 #[no_mangle]
 unsafe extern "C" fn message_get_body_type(
     message: *const RustAmqpMessage,
@@ -459,6 +720,447 @@ unsafe extern "C" fn messagebuilder_set_properties(
     }))
 }
 
+// The scalar setters below need to read the section the builder already has pending
+// before mutating a single field of it, but `AmqpMessageBuilder` only exposes the
+// consuming `with_header`/`with_properties` (see `messagebuilder_set_header` above) and
+// has no getters of its own. `build()` on a cloned builder is the cheapest way to peek
+// at what's pending, since the resulting `AmqpMessage` exposes `header()`/`properties()`
+// just like `message_get_header`/`message_get_properties` already rely on.
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_durable(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    durable: bool,
+) -> *mut RustAmqpMessageBuilder {
+    let _call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    let mut header = message_builder
+        .inner
+        .clone()
+        .build()
+        .header()
+        .cloned()
+        .unwrap_or_default();
+    header.durable = durable;
+    Box::into_raw(Box::new(RustAmqpMessageBuilder {
+        inner: message_builder.inner.with_header(header),
+    }))
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_priority(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    priority: u8,
+) -> *mut RustAmqpMessageBuilder {
+    let _call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    let mut header = message_builder
+        .inner
+        .clone()
+        .build()
+        .header()
+        .cloned()
+        .unwrap_or_default();
+    header.priority = Some(priority);
+    Box::into_raw(Box::new(RustAmqpMessageBuilder {
+        inner: message_builder.inner.with_header(header),
+    }))
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_time_to_live(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    time_to_live_in_milliseconds: u32,
+) -> *mut RustAmqpMessageBuilder {
+    let _call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    let mut header = message_builder
+        .inner
+        .clone()
+        .build()
+        .header()
+        .cloned()
+        .unwrap_or_default();
+    header.time_to_live = Some(time_to_live_in_milliseconds);
+    Box::into_raw(Box::new(RustAmqpMessageBuilder {
+        inner: message_builder.inner.with_header(header),
+    }))
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_first_acquirer(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    first_acquirer: bool,
+) -> *mut RustAmqpMessageBuilder {
+    let _call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    let mut header = message_builder
+        .inner
+        .clone()
+        .build()
+        .header()
+        .cloned()
+        .unwrap_or_default();
+    header.first_acquirer = first_acquirer;
+    Box::into_raw(Box::new(RustAmqpMessageBuilder {
+        inner: message_builder.inner.with_header(header),
+    }))
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_delivery_count(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    delivery_count: u32,
+) -> *mut RustAmqpMessageBuilder {
+    let _call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    let mut header = message_builder
+        .inner
+        .clone()
+        .build()
+        .header()
+        .cloned()
+        .unwrap_or_default();
+    header.delivery_count = Some(delivery_count);
+    Box::into_raw(Box::new(RustAmqpMessageBuilder {
+        inner: message_builder.inner.with_header(header),
+    }))
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_message_id(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    message_id: *const RustAmqpValue,
+) -> *mut RustAmqpMessageBuilder {
+    let _call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    let message_id = &*message_id;
+    let mut properties = message_builder
+        .inner
+        .clone()
+        .build()
+        .properties()
+        .cloned()
+        .unwrap_or_default();
+    properties.message_id = Some(message_id.inner.clone().into());
+    Box::into_raw(Box::new(RustAmqpMessageBuilder {
+        inner: message_builder.inner.with_properties(properties),
+    }))
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_correlation_id(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    correlation_id: *const RustAmqpValue,
+) -> *mut RustAmqpMessageBuilder {
+    let _call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    let correlation_id = &*correlation_id;
+    let mut properties = message_builder
+        .inner
+        .clone()
+        .build()
+        .properties()
+        .cloned()
+        .unwrap_or_default();
+    properties.correlation_id = Some(correlation_id.inner.clone().into());
+    Box::into_raw(Box::new(RustAmqpMessageBuilder {
+        inner: message_builder.inner.with_properties(properties),
+    }))
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_to(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    to: *const u8,
+    to_size: usize,
+) -> *mut RustAmqpMessageBuilder {
+    let call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    match std::str::from_utf8(std::slice::from_raw_parts(to, to_size)) {
+        Ok(to) => {
+            let mut properties = message_builder
+                .inner
+                .clone()
+                .build()
+                .properties()
+                .cloned()
+                .unwrap_or_default();
+            properties.to = Some(to.to_string());
+            Box::into_raw(Box::new(RustAmqpMessageBuilder {
+                inner: message_builder.inner.with_properties(properties),
+            }))
+        }
+        Err(_) => {
+            call_context.set_error(error_from_str("To must be a valid UTF-8 string"));
+            std::ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_subject(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    subject: *const u8,
+    subject_size: usize,
+) -> *mut RustAmqpMessageBuilder {
+    let call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    match std::str::from_utf8(std::slice::from_raw_parts(subject, subject_size)) {
+        Ok(subject) => {
+            let mut properties = message_builder
+                .inner
+                .clone()
+                .build()
+                .properties()
+                .cloned()
+                .unwrap_or_default();
+            properties.subject = Some(subject.to_string());
+            Box::into_raw(Box::new(RustAmqpMessageBuilder {
+                inner: message_builder.inner.with_properties(properties),
+            }))
+        }
+        Err(_) => {
+            call_context.set_error(error_from_str("Subject must be a valid UTF-8 string"));
+            std::ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_reply_to(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    reply_to: *const u8,
+    reply_to_size: usize,
+) -> *mut RustAmqpMessageBuilder {
+    let call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    match std::str::from_utf8(std::slice::from_raw_parts(reply_to, reply_to_size)) {
+        Ok(reply_to) => {
+            let mut properties = message_builder
+                .inner
+                .clone()
+                .build()
+                .properties()
+                .cloned()
+                .unwrap_or_default();
+            properties.reply_to = Some(reply_to.to_string());
+            Box::into_raw(Box::new(RustAmqpMessageBuilder {
+                inner: message_builder.inner.with_properties(properties),
+            }))
+        }
+        Err(_) => {
+            call_context.set_error(error_from_str("ReplyTo must be a valid UTF-8 string"));
+            std::ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_content_type(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    content_type: *const u8,
+    content_type_size: usize,
+) -> *mut RustAmqpMessageBuilder {
+    let call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    match std::str::from_utf8(std::slice::from_raw_parts(
+        content_type,
+        content_type_size,
+    )) {
+        Ok(content_type) => {
+            let mut properties = message_builder
+                .inner
+                .clone()
+                .build()
+                .properties()
+                .cloned()
+                .unwrap_or_default();
+            properties.content_type = Some(content_type.to_string());
+            Box::into_raw(Box::new(RustAmqpMessageBuilder {
+                inner: message_builder.inner.with_properties(properties),
+            }))
+        }
+        Err(_) => {
+            call_context.set_error(error_from_str("ContentType must be a valid UTF-8 string"));
+            std::ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_content_encoding(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    content_encoding: *const u8,
+    content_encoding_size: usize,
+) -> *mut RustAmqpMessageBuilder {
+    let call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    match std::str::from_utf8(std::slice::from_raw_parts(
+        content_encoding,
+        content_encoding_size,
+    )) {
+        Ok(content_encoding) => {
+            let mut properties = message_builder
+                .inner
+                .clone()
+                .build()
+                .properties()
+                .cloned()
+                .unwrap_or_default();
+            properties.content_encoding = Some(content_encoding.to_string());
+            Box::into_raw(Box::new(RustAmqpMessageBuilder {
+                inner: message_builder.inner.with_properties(properties),
+            }))
+        }
+        Err(_) => {
+            call_context.set_error(error_from_str(
+                "ContentEncoding must be a valid UTF-8 string",
+            ));
+            std::ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_absolute_expiry_time(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    absolute_expiry_time_in_milliseconds: i64,
+) -> *mut RustAmqpMessageBuilder {
+    let _call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    let mut properties = message_builder
+        .inner
+        .clone()
+        .build()
+        .properties()
+        .cloned()
+        .unwrap_or_default();
+    properties.absolute_expiry_time = Some(AmqpTimestamp(absolute_expiry_time_in_milliseconds));
+    Box::into_raw(Box::new(RustAmqpMessageBuilder {
+        inner: message_builder.inner.with_properties(properties),
+    }))
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_creation_time(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    creation_time_in_milliseconds: i64,
+) -> *mut RustAmqpMessageBuilder {
+    let _call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    let mut properties = message_builder
+        .inner
+        .clone()
+        .build()
+        .properties()
+        .cloned()
+        .unwrap_or_default();
+    properties.creation_time = Some(AmqpTimestamp(creation_time_in_milliseconds));
+    Box::into_raw(Box::new(RustAmqpMessageBuilder {
+        inner: message_builder.inner.with_properties(properties),
+    }))
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_group_id(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    group_id: *const u8,
+    group_id_size: usize,
+) -> *mut RustAmqpMessageBuilder {
+    let call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    match std::str::from_utf8(std::slice::from_raw_parts(group_id, group_id_size)) {
+        Ok(group_id) => {
+            let mut properties = message_builder
+                .inner
+                .clone()
+                .build()
+                .properties()
+                .cloned()
+                .unwrap_or_default();
+            properties.group_id = Some(group_id.to_string());
+            Box::into_raw(Box::new(RustAmqpMessageBuilder {
+                inner: message_builder.inner.with_properties(properties),
+            }))
+        }
+        Err(_) => {
+            call_context.set_error(error_from_str("GroupId must be a valid UTF-8 string"));
+            std::ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_group_sequence(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    group_sequence: u32,
+) -> *mut RustAmqpMessageBuilder {
+    let _call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    let mut properties = message_builder
+        .inner
+        .clone()
+        .build()
+        .properties()
+        .cloned()
+        .unwrap_or_default();
+    properties.group_sequence = Some(group_sequence);
+    Box::into_raw(Box::new(RustAmqpMessageBuilder {
+        inner: message_builder.inner.with_properties(properties),
+    }))
+}
+
+#[no_mangle]
+unsafe extern "C" fn messagebuilder_set_reply_to_group_id(
+    call_context: *mut RustCallContext,
+    message_builder: *mut RustAmqpMessageBuilder,
+    reply_to_group_id: *const u8,
+    reply_to_group_id_size: usize,
+) -> *mut RustAmqpMessageBuilder {
+    let call_context = call_context_from_ptr_mut(call_context);
+    let message_builder = Box::from_raw(message_builder);
+    match std::str::from_utf8(std::slice::from_raw_parts(
+        reply_to_group_id,
+        reply_to_group_id_size,
+    )) {
+        Ok(reply_to_group_id) => {
+            let mut properties = message_builder
+                .inner
+                .clone()
+                .build()
+                .properties()
+                .cloned()
+                .unwrap_or_default();
+            properties.reply_to_group_id = Some(reply_to_group_id.to_string());
+            Box::into_raw(Box::new(RustAmqpMessageBuilder {
+                inner: message_builder.inner.with_properties(properties),
+            }))
+        }
+        Err(_) => {
+            call_context.set_error(error_from_str(
+                "ReplyToGroupId must be a valid UTF-8 string",
+            ));
+            std::ptr::null_mut()
+        }
+    }
+}
+
 #[no_mangle]
 unsafe extern "C" fn messagebuilder_add_body_amqp_data(
     call_context: *mut RustCallContext,